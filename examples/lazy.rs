@@ -33,6 +33,8 @@ inventory::collect!(Flag);
 inventory::submit!(ServiceWrapper {
     service: fake,
     id: 3,
+    name: "fake",
+    slots: 1,
 });
 
 fn main() {
@@ -44,6 +44,8 @@ extern crate alloc;
 use alloc::boxed::Box;
 use alloc::collections::BTreeMap;
 use alloc::string::String;
+use core::future::Future;
+use core::pin::Pin;
 pub use inventory::{iter, submit};
 pub use paste::paste;
 pub use systable_macro_derive::syscall_func;
@@ -115,6 +117,74 @@ where
     }
 }
 
+/// Uniform async function
+pub trait UniFnAsync<Args, Fut> {
+    /// Call the function, returning the future it produces
+    fn call(&self, args: Args) -> Fut;
+}
+
+macro_rules! unifn_async_tuple {
+    ($(($arg:ident,$n:tt)),+) => {
+        impl<T,$($arg,)+ Fut> UniFnAsync<($($arg,)+),Fut> for T
+        where
+            T: Fn($($arg,)+)->Fut
+        {
+            fn call(&self,args:($($arg,)+))->Fut{
+                (self)($(args.$n,)+)
+            }
+        }
+    };
+}
+impl<T, Fut> UniFnAsync<(), Fut> for T
+where
+    T: Fn() -> Fut,
+{
+    fn call(&self, _: ()) -> Fut {
+        (self)()
+    }
+}
+unifn_async_tuple!((P0, 0));
+unifn_async_tuple!((P0, 0), (P1, 1));
+unifn_async_tuple!((P0, 0), (P1, 1), (P2, 2));
+unifn_async_tuple!((P0, 0), (P1, 1), (P2, 2), (P3, 3));
+unifn_async_tuple!((P0, 0), (P1, 1), (P2, 2), (P3, 3), (P4, 4));
+unifn_async_tuple!((P0, 0), (P1, 1), (P2, 2), (P3, 3), (P4, 4), (P5, 5));
+unifn_async_tuple!(
+    (P0, 0),
+    (P1, 1),
+    (P2, 2),
+    (P3, 3),
+    (P4, 4),
+    (P5, 5),
+    (P6, 6)
+);
+
+/// A wrapper of uniform async function
+#[derive(Copy, Clone)]
+pub struct AsyncSysCallHandler<F, Args, Fut> {
+    func: F,
+    _args: core::marker::PhantomData<Args>,
+    _fut: core::marker::PhantomData<Fut>,
+}
+
+impl<F, Args, Fut> AsyncSysCallHandler<F, Args, Fut>
+where
+    F: UniFnAsync<Args, Fut>,
+{
+    /// Create a new AsyncSysCallHandler
+    pub const fn new(func: F) -> Self {
+        Self {
+            func,
+            _args: core::marker::PhantomData,
+            _fut: core::marker::PhantomData,
+        }
+    }
+    /// Call the function, returning the future it produces
+    pub fn call(&self, args: Args) -> Fut {
+        self.func.call(args)
+    }
+}
+
 /// Trait for converting to isize
 pub trait ToIsize {
     /// Convert to isize
@@ -155,6 +225,45 @@ impl<T:ToIsize, E: ToIsize> ToIsize for Result<T, E> {
     }
 }
 
+/// Trait for a handler result that writes a value back to the caller through
+/// an out-pointer argument before yielding its status code
+///
+/// No `impl<T> ToArgs for (isize, T)`: the out-pointer is gone from `Args` by
+/// the time the handler returns, so pair it explicitly via [`WithOut::new`].
+pub trait ToArgs {
+    /// Write the produced value back through the out-pointer, returning the
+    /// status code the syscall should return
+    fn write_back(self) -> isize;
+}
+
+/// A handler result pairing a status code with a value to write back through
+/// an out-pointer argument (see [`OutPtr`])
+pub struct WithOut<T> {
+    ptr: *mut T,
+    status: isize,
+    value: T,
+}
+
+impl<T> WithOut<T> {
+    /// Pair a status code and value with the out-pointer to write them back through
+    pub fn new(out: OutPtr<T>, status: isize, value: T) -> Self {
+        Self {
+            ptr: out.as_ptr(),
+            status,
+            value,
+        }
+    }
+}
+
+impl<T> ToArgs for WithOut<T> {
+    fn write_back(self) -> isize {
+        if !self.ptr.is_null() {
+            unsafe { self.ptr.write(self.value) };
+        }
+        self.status
+    }
+}
+
 /// Trait for converting to usize
 pub trait ToUsize {
     /// Convert to usize
@@ -210,20 +319,57 @@ impl<T> ToUsize for &mut T {
     }
 }
 
+/// A consuming cursor over the raw `&[usize]` argument slots of a syscall
+pub struct ArgCursor<'a> {
+    args: &'a [usize],
+    pos: usize,
+}
+
+impl<'a> ArgCursor<'a> {
+    /// Create a cursor over the given argument slots
+    pub fn new(args: &'a [usize]) -> Self {
+        Self { args, pos: 0 }
+    }
+    /// Take the next `n` slots and advance the cursor past them
+    ///
+    /// Returns fewer than `n` slots (possibly none) if the underlying
+    /// argument array is exhausted; callers are expected to check the
+    /// returned length and report an error themselves.
+    pub fn take(&mut self, n: usize) -> &'a [usize] {
+        let end = core::cmp::min(self.pos + n, self.args.len());
+        let res = &self.args[self.pos..end];
+        self.pos = end;
+        res
+    }
+}
+
 /// Trait for converting arguments
 pub trait FromArgs: Sized {
-    /// Convert arguments
-    fn from(args: &[usize]) -> Result<Self, String>;
+    /// The number of raw `args` slots this type consumes from an [`ArgCursor`]
+    const SLOTS: usize;
+    /// Convert arguments from a fixed `&[usize]` array
+    ///
+    /// Kept for compatibility; it simply drives [`Self::from_cursor`] over a
+    /// fresh [`ArgCursor`].
+    fn from(args: &[usize]) -> Result<Self, String> {
+        let mut cursor = ArgCursor::new(args);
+        Self::from_cursor(&mut cursor)
+    }
+    /// Convert arguments, consuming as many slots as needed from `cur`
+    fn from_cursor(cur: &mut ArgCursor) -> Result<Self, String>;
 }
 
 impl FromArgs for () {
-    fn from(_: &[usize]) -> Result<Self, String> {
+    const SLOTS: usize = 0;
+    fn from_cursor(_cur: &mut ArgCursor) -> Result<Self, String> {
         Ok(())
     }
 }
 
 impl<T> FromArgs for *const T {
-    fn from(args: &[usize]) -> Result<Self, String> {
+    const SLOTS: usize = 1;
+    fn from_cursor(cur: &mut ArgCursor) -> Result<Self, String> {
+        let args = cur.take(1);
         if args.len() >= 1 {
             let res = args[0] as *const T;
             Ok(res)
@@ -234,7 +380,9 @@ impl<T> FromArgs for *const T {
 }
 
 impl<T> FromArgs for *mut T {
-    fn from(args: &[usize]) -> Result<Self, String> {
+    const SLOTS: usize = 1;
+    fn from_cursor(cur: &mut ArgCursor) -> Result<Self, String> {
+        let args = cur.take(1);
         if args.len() >= 1 {
             let res = args[0] as *mut T;
             Ok(res)
@@ -247,7 +395,9 @@ impl<T> FromArgs for *mut T {
 macro_rules! mark_basic_type {
     ($ident:ty) => {
         impl FromArgs for $ident {
-            fn from(args: &[usize]) -> Result<Self, String> {
+            const SLOTS: usize = 1;
+            fn from_cursor(cur: &mut ArgCursor) -> Result<Self, String> {
+                let args = cur.take(1);
                 if args.len() >= 1 {
                     let res = args[0] as $ident;
                     Ok(res)
@@ -272,14 +422,121 @@ mark_basic_type!(i32);
 mark_basic_type!(i16);
 mark_basic_type!(i8);
 
+/// A user-space slice argument, decoded from a `(ptr, len)` pair of slots
+pub struct UserSlice<T> {
+    ptr: *const T,
+    len: usize,
+}
+
+impl<T> UserSlice<T> {
+    /// Rebuild the `&[T]` described by this argument
+    ///
+    /// # Safety
+    /// The caller must ensure `ptr` and `len` describe a valid, initialized
+    /// `[T]` for the lifetime of the returned reference.
+    pub unsafe fn as_slice<'a>(&self) -> &'a [T] {
+        core::slice::from_raw_parts(self.ptr, self.len)
+    }
+}
+
+impl<T> FromArgs for UserSlice<T> {
+    const SLOTS: usize = 2;
+    fn from_cursor(cur: &mut ArgCursor) -> Result<Self, String> {
+        let args = cur.take(2);
+        if args.len() < 2 {
+            return Err(alloc::string::String::from("UserSlice:args.len() < 2"));
+        }
+        let ptr = args[0] as *const T;
+        let len = args[1];
+        if len == 0 {
+            return Err(alloc::string::String::from("UserSlice:len == 0"));
+        }
+        if len.checked_mul(core::mem::size_of::<T>()).is_none() {
+            return Err(alloc::string::String::from("UserSlice:len overflows"));
+        }
+        Ok(UserSlice { ptr, len })
+    }
+}
+
+/// A user-space `&str` argument, decoded from a `(ptr, len)` pair of slots
+pub struct UserStr {
+    ptr: *const u8,
+    len: usize,
+}
+
+impl UserStr {
+    /// Rebuild the `&str` described by this argument
+    ///
+    /// # Safety
+    /// The caller must ensure `ptr` and `len` describe a valid, initialized,
+    /// UTF-8 encoded byte buffer for the lifetime of the returned reference.
+    pub unsafe fn as_str<'a>(&self) -> Result<&'a str, core::str::Utf8Error> {
+        let bytes = core::slice::from_raw_parts(self.ptr, self.len);
+        core::str::from_utf8(bytes)
+    }
+}
+
+impl FromArgs for UserStr {
+    const SLOTS: usize = 2;
+    fn from_cursor(cur: &mut ArgCursor) -> Result<Self, String> {
+        let args = cur.take(2);
+        if args.len() < 2 {
+            return Err(alloc::string::String::from("UserStr:args.len() < 2"));
+        }
+        let ptr = args[0] as *const u8;
+        let len = args[1];
+        if len == 0 {
+            return Err(alloc::string::String::from("UserStr:len == 0"));
+        }
+        Ok(UserStr { ptr, len })
+    }
+}
+
+/// An out-pointer argument, decoded like any other `*mut T` argument but
+/// paired with [`ToArgs`]/[`WithOut`] so a handler's result can be written
+/// back through it after the handler runs
+pub struct OutPtr<T> {
+    ptr: *mut T,
+}
+
+impl<T> Clone for OutPtr<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for OutPtr<T> {}
+
+impl<T> OutPtr<T> {
+    /// The raw destination pointer
+    pub fn as_ptr(&self) -> *mut T {
+        self.ptr
+    }
+}
+
+impl<T> FromArgs for OutPtr<T> {
+    const SLOTS: usize = 1;
+    fn from_cursor(cur: &mut ArgCursor) -> Result<Self, String> {
+        let args = cur.take(1);
+        if args.len() >= 1 {
+            Ok(OutPtr {
+                ptr: args[0] as *mut T,
+            })
+        } else {
+            Err(alloc::string::String::from("OutPtr:args.len() < 1"))
+        }
+    }
+}
+
 macro_rules! from_args_tuple {
     ($(($arg:ident,$n:tt)),+) => {
         impl<$($arg,)+> FromArgs for ($($arg,)+)
         where
             $($arg:FromArgs,)+
         {
-            fn from(args:&[usize])->Result<Self,String>{
-                $(let $arg = $arg::from(&args[$n..])?;)+
+            const SLOTS: usize = 0 $(+ $arg::SLOTS)+;
+            fn from_cursor(cur:&mut ArgCursor)->Result<Self,String>{
+                $(let $arg = $arg::from_cursor(cur)?;)+
                 Ok(($($arg,)+))
             }
         }
@@ -293,10 +550,37 @@ from_args_tuple!((P0, 0), (P1, 1), (P2, 2), (P3, 3));
 from_args_tuple!((P0, 0), (P1, 1), (P2, 2), (P3, 3), (P4, 4));
 from_args_tuple!((P0, 0), (P1, 1), (P2, 2), (P3, 3), (P4, 4), (P5, 5));
 
+/// Error returned by the fallible dispatch path
+#[derive(Debug)]
+pub enum SyscallError {
+    /// No service is registered for the requested syscall id (like `-ENOSYS`)
+    NoSuchSyscall,
+    /// Argument decoding failed (like `-EINVAL`); carries the `FromArgs` error message
+    InvalidArgs(String),
+}
+
+impl SyscallError {
+    /// Convert the error into the conventional negative-`isize` errno value
+    pub fn to_errno(&self) -> isize {
+        match self {
+            // -ENOSYS
+            SyscallError::NoSuchSyscall => -38,
+            // -EINVAL
+            SyscallError::InvalidArgs(_) => -22,
+        }
+    }
+}
+
 /// The wrapper of syscall handler
 pub struct Service {
     /// The handler of syscall
-    service: Box<dyn Fn(&[usize]) -> isize>,
+    service: Box<dyn Fn(&[usize]) -> Result<isize, SyscallError>>,
+    /// The registered function's own type name, used by [`Table::signature`]/
+    /// [`Table::do_call_traced`] so tracing reports exactly what this Service
+    /// dispatches to, not an unrelated registration elsewhere in the process
+    name: &'static str,
+    /// The number of raw `args` slots the registered function's `Args` consumes
+    slots: usize,
 }
 
 impl Service {
@@ -311,25 +595,92 @@ impl Service {
     {
         Self {
             service: Box::new(move |args: &[usize]| {
-                let args = Args::from(args).unwrap();
-                handler.call(args).to_isize()
+                let args = Args::from(args).map_err(SyscallError::InvalidArgs)?;
+                Ok(handler.call(args).to_isize())
+            }),
+            name: core::any::type_name::<F>(),
+            slots: Args::SLOTS,
+        }
+    }
+    /// Create a new Service whose result writes a value back through an
+    /// out-pointer argument (see [`ToArgs`], [`OutPtr`], [`WithOut`]) before
+    /// yielding its status code
+    pub fn from_out_handler<F, Args, Res>(handler: SysCallHandler<F, Args, Res>) -> Self
+    where
+        F: UniFn<Args, Res> + 'static,
+        Args: FromArgs + 'static,
+        Res: ToArgs + 'static,
+    {
+        Self {
+            service: Box::new(move |args: &[usize]| {
+                let decoded = Args::from(args).map_err(SyscallError::InvalidArgs)?;
+                let res = handler.call(decoded);
+                Ok(res.write_back())
             }),
+            name: core::any::type_name::<F>(),
+            slots: Args::SLOTS,
         }
     }
+    /// Call the service, surfacing argument decode failures instead of panicking
+    pub fn try_handle(&self, args: &[usize]) -> Result<isize, SyscallError> {
+        (self.service)(args)
+    }
     /// Call the service
     pub fn handle(&self, args: &[usize]) -> isize {
-        (self.service)(args)
+        self.try_handle(args).unwrap()
     }
 }
 
 unsafe impl Send for Service {}
 unsafe impl Sync for Service {}
 
+/// The async counterpart of [`Service`], for handlers that return a [`Future`]
+pub struct AsyncService {
+    /// The handler of syscall
+    service: Box<dyn Fn(&[usize]) -> Pin<Box<dyn Future<Output = isize>>>>,
+}
+
+impl AsyncService {
+    /// Create a new AsyncService
+    ///
+    /// The AsyncSysCallHandler will be put into a closure, thus erasing the
+    /// function parameter information
+    pub fn from_async_handler<F, Args, Fut, Res>(handler: AsyncSysCallHandler<F, Args, Fut>) -> Self
+    where
+        F: UniFnAsync<Args, Fut> + 'static,
+        Args: FromArgs + 'static,
+        Fut: Future<Output = Res> + 'static,
+        Res: ToIsize + 'static,
+    {
+        Self {
+            service: Box::new(move |args: &[usize]| match Args::from(args) {
+                Ok(decoded) => {
+                    let fut = handler.call(decoded);
+                    Box::pin(async move { fut.await.to_isize() }) as Pin<Box<dyn Future<Output = isize>>>
+                }
+                Err(msg) => {
+                    let code = SyscallError::InvalidArgs(msg).to_errno();
+                    Box::pin(async move { code }) as Pin<Box<dyn Future<Output = isize>>>
+                }
+            }),
+        }
+    }
+    /// Start the service, returning the future that yields its result; the
+    /// caller's executor drives it to completion
+    pub fn handle(&self, args: &[usize]) -> Pin<Box<dyn Future<Output = isize>>> {
+        (self.service)(args)
+    }
+}
+
+unsafe impl Send for AsyncService {}
+unsafe impl Sync for AsyncService {}
+
 /// A container for Service
 ///
 /// The key is the specific number
 pub struct Table {
     map: BTreeMap<usize, Service>,
+    async_map: BTreeMap<usize, AsyncService>,
 }
 
 impl Table {
@@ -337,6 +688,7 @@ impl Table {
     pub const fn new() -> Self {
         Self {
             map: BTreeMap::new(),
+            async_map: BTreeMap::new(),
         }
     }
     /// Register a function
@@ -349,14 +701,102 @@ impl Table {
         let handler = SysCallHandler::new(func);
         self.map.insert(id, Service::from_handler(handler));
     }
+    /// Register a function whose result writes a value back through an
+    /// out-pointer argument (see [`ToArgs`], [`OutPtr`], [`WithOut`])
+    pub fn register_with_out<F, Args, Res>(&mut self, id: usize, func: F)
+    where
+        F: UniFn<Args, Res> + 'static,
+        Args: FromArgs + 'static,
+        Res: ToArgs + 'static,
+    {
+        let handler = SysCallHandler::new(func);
+        self.map.insert(id, Service::from_out_handler(handler));
+    }
+    /// Register an async function, driven by a `no_std` executor instead of
+    /// resolving immediately
+    pub fn register_async<F, Args, Fut, Res>(&mut self, id: usize, func: F)
+    where
+        F: UniFnAsync<Args, Fut> + 'static,
+        Args: FromArgs + 'static,
+        Fut: Future<Output = Res> + 'static,
+        Res: ToIsize + 'static,
+    {
+        let handler = AsyncSysCallHandler::new(func);
+        self.async_map.insert(id, AsyncService::from_async_handler(handler));
+    }
     /// Remove a function
     pub fn remove(&mut self, id: usize) -> Option<Service> {
         self.map.remove(&id)
     }
 
+    /// Call the function, surfacing "no such id" and argument decode failures
+    /// as a [`SyscallError`] instead of panicking
+    pub fn try_do_call(&self, id: usize, args: &[usize]) -> Result<isize, SyscallError> {
+        match self.map.get(&id) {
+            Some(service) => service.try_handle(args),
+            None => Err(SyscallError::NoSuchSyscall),
+        }
+    }
+
     /// call the function
     pub fn do_call(&self, id: usize, args: &[usize]) -> Option<isize> {
-        self.map.get(&id).map(|x| x.handle(args))
+        match self.try_do_call(id, args) {
+            Ok(res) => Some(res),
+            Err(SyscallError::NoSuchSyscall) => None,
+            Err(SyscallError::InvalidArgs(msg)) => panic!("{}", msg),
+        }
+    }
+
+    /// Resolve a syscall id back to the type name and declared slot count of
+    /// the function actually registered for it via [`Self::register`]/
+    /// [`Self::register_with_out`]
+    ///
+    /// Sourced from the `Service` stored in this `Table`, not from the
+    /// process-global `#[syscall_func]`/`inventory::submit!` registry, so it
+    /// can't report an unrelated function that merely shares the same id
+    /// elsewhere in the process.
+    pub fn signature(&self, id: usize) -> Option<(&'static str, usize)> {
+        self.map.get(&id).map(|service| (service.name, service.slots))
+    }
+
+    /// Call the function, logging `name(arg0, arg1, ...) = ret` like `strace`
+    ///
+    /// Only observable under the `test` feature: this crate has no
+    /// no_std-compatible logging dependency to print through (no
+    /// `Cargo.toml` exists yet to declare one), so in a real no_std kernel
+    /// build this still dispatches but prints nothing. Revisit once a
+    /// manifest exists to wire a proper `log`-facade `trace` feature.
+    pub fn do_call_traced(&self, id: usize, args: &[usize]) -> Option<isize> {
+        let result = self.try_do_call(id, args);
+        #[cfg(feature = "test")]
+        {
+            extern crate std;
+            let ret = match &result {
+                Ok(v) => *v,
+                Err(e) => e.to_errno(),
+            };
+            let list = |slots: &[usize]| {
+                slots
+                    .iter()
+                    .map(|a| alloc::format!("{}", a))
+                    .collect::<alloc::vec::Vec<String>>()
+                    .join(", ")
+            };
+            match self.signature(id) {
+                Some((name, slots)) => {
+                    let shown = &args[..core::cmp::min(slots, args.len())];
+                    std::println!("{}({}) = {}", name, list(shown), ret);
+                }
+                None => std::println!("{}({}) = {}", id, list(args), ret),
+            }
+        }
+        result.ok()
+    }
+
+    /// Call an async function registered with [`Self::register_async`],
+    /// returning a future the caller's executor drives to completion
+    pub fn do_call_async(&self, id: usize, args: &[usize]) -> Option<Pin<Box<dyn Future<Output = isize>>>> {
+        self.async_map.get(&id).map(|service| service.handle(args))
     }
 }
 
@@ -436,6 +876,12 @@ pub struct ServiceWrapper {
     pub service: fn(&[usize]) -> isize,
     /// The id
     pub id: u16,
+    /// The original function's name, as written at the `#[syscall_func]` site
+    pub name: &'static str,
+    /// The number of raw `args` slots the original function declares, not
+    /// its Rust parameter count: a multi-slot argument like
+    /// [`UserSlice`]/[`UserStr`] counts for 2
+    pub slots: usize,
 }
 
 inventory::collect!(ServiceWrapper);
@@ -486,9 +932,17 @@ macro_rules! init_init_array {
 #[cfg(test)]
 mod tests {
     extern crate std;
-    use super::Table;
+    use super::{
+        ArgCursor, FromArgs, OutPtr, ServiceWrapper, SyscallError, Table, ToArgs, UserSlice,
+        UserStr, WithOut,
+    };
+    use alloc::boxed::Box;
+    use core::future::Future;
+    use core::pin::Pin;
+    use core::task::{Context, Poll, RawWaker, RawWakerVTable, Waker};
     use std::println;
     use std::vec::Vec;
+
     fn read(p1: usize, p2: usize) -> isize {
         println!("p1+p2 = {}", p1 + p2);
         0
@@ -532,4 +986,135 @@ mod tests {
         let v = table.do_call(2, &[2, 4]);
         assert_eq!(v, Some(6));
     }
+
+    #[test]
+    fn scalar_after_user_slice_lands_at_right_cursor_position() {
+        let data = [1usize, 2, 3];
+        let args = [data.as_ptr() as usize, data.len(), 99usize];
+        let (slice, scalar) = <(UserSlice<usize>, usize) as FromArgs>::from(&args).unwrap();
+        assert_eq!(unsafe { slice.as_slice() }, &data[..]);
+        assert_eq!(scalar, 99);
+    }
+
+    #[test]
+    fn scalar_after_user_str_lands_at_right_cursor_position() {
+        let path = "/tmp";
+        let args = [path.as_ptr() as usize, path.len(), 7usize];
+        let (s, scalar) = <(UserStr, usize) as FromArgs>::from(&args).unwrap();
+        assert_eq!(unsafe { s.as_str() }.unwrap(), path);
+        assert_eq!(scalar, 7);
+    }
+
+    #[test]
+    fn user_slice_rejects_zero_len_and_overflowing_len() {
+        let data = [1usize];
+        let zero_len = [data.as_ptr() as usize, 0usize];
+        let mut cur = ArgCursor::new(&zero_len);
+        assert!(UserSlice::<usize>::from_cursor(&mut cur).is_err());
+
+        let overflow_len = [data.as_ptr() as usize, usize::MAX];
+        let mut cur = ArgCursor::new(&overflow_len);
+        assert!(UserSlice::<usize>::from_cursor(&mut cur).is_err());
+    }
+
+    #[test]
+    fn user_str_rejects_zero_len() {
+        let data = [1u8];
+        let zero_len = [data.as_ptr() as usize, 0usize];
+        let mut cur = ArgCursor::new(&zero_len);
+        assert!(UserStr::from_cursor(&mut cur).is_err());
+    }
+
+    #[test]
+    fn out_ptr_write_back_writes_through_decoded_pointer() {
+        let mut val: u32 = 0;
+        let args = [&mut val as *mut u32 as usize];
+        let mut cur = ArgCursor::new(&args);
+        let out = OutPtr::<u32>::from_cursor(&mut cur).unwrap();
+        let status = WithOut::new(out, 0, 42u32).write_back();
+        assert_eq!(status, 0);
+        assert_eq!(val, 42);
+    }
+
+    #[test]
+    fn try_do_call_distinguishes_no_such_syscall_from_invalid_args() {
+        let mut table = Table::new();
+        table.register(0, add);
+        match table.try_do_call(99, &[1, 2]) {
+            Err(SyscallError::NoSuchSyscall) => {}
+            other => panic!("expected NoSuchSyscall, got {:?}", other),
+        }
+        match table.try_do_call(0, &[1]) {
+            Err(SyscallError::InvalidArgs(_)) => {}
+            other => panic!("expected InvalidArgs, got {:?}", other),
+        }
+        assert_eq!(table.try_do_call(0, &[2, 4]).unwrap(), 6);
+    }
+
+    fn unrelated_syscall(_args: &[usize]) -> isize {
+        0
+    }
+
+    // A ServiceWrapper submitted under the same id as the Table registration
+    // below, but for a completely different function. Table::signature/
+    // do_call_traced must not pick this up: it isn't registered in any
+    // Table, just sitting in the process-global inventory.
+    inventory::submit!(ServiceWrapper {
+        service: unrelated_syscall,
+        id: 50,
+        name: "unrelated_syscall",
+        slots: 99,
+    });
+
+    fn type_name_of<F>(_: F) -> &'static str {
+        core::any::type_name::<F>()
+    }
+
+    #[test]
+    fn signature_and_do_call_traced_report_the_registered_functions_own_metadata() {
+        let mut table = Table::new();
+        table.register(50, add);
+        let (name, slots) = table.signature(50).unwrap();
+        assert_eq!(name, type_name_of(add));
+        assert_ne!(name, "unrelated_syscall");
+        assert_eq!(slots, 2);
+        assert!(table.signature(999).is_none());
+        assert_eq!(table.do_call_traced(50, &[2, 4, 99]), Some(6));
+        assert_eq!(table.do_call_traced(999, &[1]), None);
+    }
+
+    async fn add_async(a: usize, b: usize) -> isize {
+        (a + b) as isize
+    }
+
+    fn noop_waker() -> Waker {
+        fn no_op(_: *const ()) {}
+        fn clone(_: *const ()) -> RawWaker {
+            raw_waker()
+        }
+        static VTABLE: RawWakerVTable = RawWakerVTable::new(clone, no_op, no_op, no_op);
+        fn raw_waker() -> RawWaker {
+            RawWaker::new(core::ptr::null(), &VTABLE)
+        }
+        unsafe { Waker::from_raw(raw_waker()) }
+    }
+
+    fn block_on(mut fut: Pin<Box<dyn Future<Output = isize>>>) -> isize {
+        let waker = noop_waker();
+        let mut cx = Context::from_waker(&waker);
+        loop {
+            if let Poll::Ready(v) = fut.as_mut().poll(&mut cx) {
+                return v;
+            }
+        }
+    }
+
+    #[test]
+    fn register_async_decodes_args_and_do_call_async_drives_future_to_completion() {
+        let mut table = Table::new();
+        table.register_async(60, add_async);
+        let fut = table.do_call_async(60, &[2, 4]).unwrap();
+        assert_eq!(block_on(fut), 6);
+        assert!(table.do_call_async(999, &[1]).is_none());
+    }
 }
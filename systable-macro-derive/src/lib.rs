@@ -2,9 +2,27 @@
 
 extern crate alloc;
 extern crate proc_macro;
+use alloc::string::ToString;
 use proc_macro::TokenStream;
 use quote::{format_ident, quote};
 use syn::parse_macro_input;
+
+/// The number of raw `args` slots a declared argument type consumes
+///
+/// Matches the slot widths `FromArgs` impls in `syscall_table` consume from
+/// their `ArgCursor`: most types take one slot, but `UserSlice`/`UserStr`
+/// take two (a `(ptr, len)` pair).
+fn arg_slots(ty: &syn::Type) -> usize {
+    if let syn::Type::Path(type_path) = ty {
+        if let Some(segment) = type_path.path.segments.last() {
+            if segment.ident == "UserSlice" || segment.ident == "UserStr" {
+                return 2;
+            }
+        }
+    }
+    1
+}
+
 /// Define a syscall function
 ///
 /// # Example
@@ -22,6 +40,16 @@ pub fn syscall_func(attr: TokenStream, item: TokenStream) -> TokenStream {
     let ident = format_ident!("__syscall_{}", number);
     let old_ident = input.sig.ident.clone();
     let name_ident = format_ident!("__{}", old_ident);
+    let fn_name = old_ident.to_string();
+    let slots: usize = input
+        .sig
+        .inputs
+        .iter()
+        .map(|arg| match arg {
+            syn::FnArg::Typed(pat_type) => arg_slots(&pat_type.ty),
+            syn::FnArg::Receiver(_) => 1,
+        })
+        .sum();
     let name_syscall = quote! {
         #[inline]
         #[no_mangle]
@@ -35,6 +63,8 @@ pub fn syscall_func(attr: TokenStream, item: TokenStream) -> TokenStream {
             ServiceWrapper{
                 service:#name_ident,
                 id:#number,
+                name:#fn_name,
+                slots:#slots,
             }
         );
     };